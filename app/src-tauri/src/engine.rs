@@ -1,23 +1,73 @@
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::{BufRead, BufReader};
 use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
-use std::time::Duration;
-use tauri::{AppHandle, State};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager, State};
 use tauri_plugin_shell::process::CommandChild;
 use tauri_plugin_shell::ShellExt;
 
-/// Wrapper for different process types (std::process vs tauri sidecar)
+/// Id used by the single-engine commands (`start_engine`, `stop_engine`, ...) so they
+/// keep working as thin wrappers over one entry in the instance registry.
+const DEFAULT_ENGINE_ID: &str = "default";
+
+/// Maximum number of automatic restart attempts before the supervisor gives up
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+/// Starting backoff delay between restart attempts, doubled on each subsequent attempt
+const BASE_BACKOFF_MS: u64 = 500;
+/// Upper bound on the backoff delay, regardless of attempt count
+const MAX_BACKOFF_MS: u64 = 30_000;
+/// How long an engine must stay `Running` before the retry counter is reset to 0
+const STABILITY_WINDOW: Duration = Duration::from_secs(10);
+/// How long to wait for the engine's HTTP server to come up before giving up
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Interval between readiness polls
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// Per-request timeout for a single readiness probe. Deliberately more generous than
+/// `READY_POLL_INTERVAL` so a slow-but-healthy response (cold-start GC, a loaded event
+/// loop) isn't mistaken for "not ready yet" and spun on until `READY_TIMEOUT` kills it.
+const READY_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+/// Maximum number of log lines kept in each instance's in-memory ring buffer
+const LOG_BUFFER_CAPACITY: usize = 2000;
+/// Tauri event emitted for each new engine log line
+const LOG_EVENT: &str = "engine://log";
+
+/// Severity of an engine log line, derived from which stream it arrived on
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Info,
+    Error,
+}
+
+/// A single line of engine output, emitted live and kept in the ring buffer
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogLine {
+    pub id: String,
+    pub level: LogLevel,
+    pub text: String,
+    pub ts: u64,
+}
+
+/// Wrapper for different process types (std::process vs tauri sidecar vs an engine
+/// this app merely attached to over HTTP)
 pub enum EngineProcess {
     /// Standard library Child process (used in dev mode with Python)
     StdProcess(Child),
     /// Tauri sidecar CommandChild (used in release mode)
     SidecarProcess(CommandChild),
+    /// An engine already running elsewhere (another host, a container) that this app
+    /// connected to instead of spawning. There is no child to kill.
+    Remote { base_url: String },
 }
 
 impl EngineProcess {
-    /// Kill the underlying process (consumes self since CommandChild::kill takes ownership)
+    /// Kill the underlying process (consumes self since CommandChild::kill takes ownership).
+    /// A no-op for `Remote`, since we never own that process.
     pub fn kill(self) -> std::io::Result<()> {
         match self {
             EngineProcess::StdProcess(mut child) => child.kill(),
@@ -26,18 +76,11 @@ impl EngineProcess {
                     std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
                 })
             }
+            EngineProcess::Remote { .. } => Ok(()),
         }
     }
 }
 
-/// Engine state managed by Tauri
-pub struct EngineState {
-    process: Mutex<Option<EngineProcess>>,
-    port: Mutex<u16>,
-    token: Mutex<String>,
-    status: Mutex<EngineStatus>,
-}
-
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
 pub enum EngineStatus {
     Stopped,
@@ -46,26 +89,188 @@ pub enum EngineStatus {
     Error,
 }
 
+impl EngineStatus {
+    fn to_u8(self) -> u8 {
+        match self {
+            EngineStatus::Stopped => 0,
+            EngineStatus::Starting => 1,
+            EngineStatus::Running => 2,
+            EngineStatus::Error => 3,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => EngineStatus::Starting,
+            2 => EngineStatus::Running,
+            3 => EngineStatus::Error,
+            _ => EngineStatus::Stopped,
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct EngineInfo {
+    pub id: String,
     pub port: u16,
     pub status: EngineStatus,
+    pub retry_count: u32,
+    pub last_exit_status: Option<String>,
 }
 
-impl EngineState {
-    pub fn new() -> Self {
+/// One running (or stopped) engine: its own process, port, token and supervisor state.
+/// Held behind an `Arc` in the registry so monitor/supervisor threads can keep a handle
+/// to it independent of later registry lookups.
+pub struct EngineInstance {
+    id: String,
+    process: Mutex<Option<EngineProcess>>,
+    /// Read far more often than written (every status/port poll); an atomic avoids
+    /// taking a lock, and can never be poisoned by a panic while held.
+    port: AtomicU16,
+    token: Mutex<String>,
+    status: AtomicU8,
+    /// Set by `stop_engine_instance` before killing the child, so the supervisor can
+    /// tell a deliberate stop apart from a crash and skip auto-restart.
+    shutdown_requested: AtomicBool,
+    /// Number of consecutive restart attempts since the engine last stabilized.
+    retry_count: Mutex<u32>,
+    /// Human-readable description of the last unexpected exit, if any.
+    last_exit_status: Mutex<Option<String>>,
+    /// Bounded backlog of recent log lines, so a freshly opened log panel can backfill.
+    log_buffer: Mutex<VecDeque<LogLine>>,
+}
+
+impl EngineInstance {
+    fn new(id: &str) -> Self {
         Self {
+            id: id.to_string(),
             process: Mutex::new(None),
-            port: Mutex::new(48100),
+            port: AtomicU16::new(48100),
             token: Mutex::new(String::new()),
-            status: Mutex::new(EngineStatus::Stopped),
+            status: AtomicU8::new(EngineStatus::Stopped.to_u8()),
+            shutdown_requested: AtomicBool::new(false),
+            retry_count: Mutex::new(0),
+            last_exit_status: Mutex::new(None),
+            log_buffer: Mutex::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY)),
+        }
+    }
+
+    fn port(&self) -> u16 {
+        self.port.load(Ordering::SeqCst)
+    }
+
+    fn set_port(&self, port: u16) {
+        self.port.store(port, Ordering::SeqCst);
+    }
+
+    fn status(&self) -> EngineStatus {
+        EngineStatus::from_u8(self.status.load(Ordering::SeqCst))
+    }
+
+    fn set_status(&self, status: EngineStatus) {
+        self.status.store(status.to_u8(), Ordering::SeqCst);
+    }
+
+    fn info(&self) -> EngineInfo {
+        EngineInfo {
+            id: self.id.clone(),
+            port: self.port(),
+            status: self.status(),
+            retry_count: *self.retry_count.lock().unwrap(),
+            last_exit_status: self.last_exit_status.lock().unwrap().clone(),
+        }
+    }
+}
+
+/// Registry of named engine instances, so the app can run several engines concurrently
+/// (e.g. a fast model and a heavy model side by side) instead of one global engine.
+pub struct EngineState {
+    /// Held separately from `instances` so the single-engine commands (`get_engine_port`
+    /// and friends) can reach the default instance's atomics without ever locking the
+    /// map — that lock would erase the whole point of making those fields lock-free.
+    default_instance: Arc<EngineInstance>,
+    instances: Mutex<HashMap<String, Arc<EngineInstance>>>,
+}
+
+impl EngineState {
+    pub fn new() -> Self {
+        Self {
+            default_instance: Arc::new(EngineInstance::new(DEFAULT_ENGINE_ID)),
+            instances: Mutex::new(HashMap::new()),
         }
     }
+
+    /// Get the named instance, creating a fresh (stopped) one if it doesn't exist yet.
+    fn get_or_create(&self, id: &str) -> Arc<EngineInstance> {
+        if id == DEFAULT_ENGINE_ID {
+            return self.default_instance.clone();
+        }
+        self.instances
+            .lock()
+            .unwrap()
+            .entry(id.to_string())
+            .or_insert_with(|| Arc::new(EngineInstance::new(id)))
+            .clone()
+    }
+
+    /// Ports already claimed by a live instance, so `find_available_port` skips them.
+    fn claimed_ports(&self) -> HashSet<u16> {
+        let mut ports: HashSet<u16> = self
+            .instances
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|instance| {
+                matches!(
+                    instance.status(),
+                    EngineStatus::Running | EngineStatus::Starting
+                )
+            })
+            .map(|instance| instance.port())
+            .collect();
+
+        if matches!(
+            self.default_instance.status(),
+            EngineStatus::Running | EngineStatus::Starting
+        ) {
+            ports.insert(self.default_instance.port());
+        }
+
+        ports
+    }
 }
 
-/// Find an available port in the range 48100-48199
-fn find_available_port() -> Result<u16, String> {
+/// Push a log line into the instance's ring buffer and emit it to the webview.
+fn push_log(app: &AppHandle, instance: &EngineInstance, level: LogLevel, text: String) {
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let line = LogLine {
+        id: instance.id.clone(),
+        level,
+        text,
+        ts,
+    };
+
+    {
+        let mut buffer = instance.log_buffer.lock().unwrap();
+        if buffer.len() >= LOG_BUFFER_CAPACITY {
+            buffer.pop_front();
+        }
+        buffer.push_back(line.clone());
+    }
+
+    let _ = app.emit(LOG_EVENT, line);
+}
+
+/// Find an available port in the range 48100-48199, skipping any already claimed by a
+/// live instance.
+fn find_available_port(claimed: &HashSet<u16>) -> Result<u16, String> {
     for port in 48100..48200 {
+        if claimed.contains(&port) {
+            continue;
+        }
         if std::net::TcpListener::bind(format!("127.0.0.1:{}", port)).is_ok() {
             return Ok(port);
         }
@@ -113,6 +318,8 @@ fn get_engine_module_path() -> Result<String, String> {
 
 /// Spawn engine using Python (development mode or fallback)
 fn spawn_python_engine(
+    app: &AppHandle,
+    instance: &Arc<EngineInstance>,
     port: u16,
     token: &str,
     parent_pid: &str,
@@ -120,7 +327,8 @@ fn spawn_python_engine(
 ) -> Result<EngineProcess, String> {
     let engine_path = get_engine_module_path()?;
 
-    let child = Command::new("python")
+    let mut command = Command::new("python");
+    command
         .args([
             "-m",
             "engine.main",
@@ -134,21 +342,55 @@ fn spawn_python_engine(
             log_level,
         ])
         .env("PYTHONPATH", &engine_path)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Prevent a console window from flashing up on every launch; bundled sidecars
+    // don't have this problem since they aren't spawned through a console subsystem.
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command
         .spawn()
         .map_err(|e| format!("Failed to spawn Python engine: {}", e))?;
 
+    if let Some(stdout) = child.stdout.take() {
+        let app = app.clone();
+        let instance = instance.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                push_log(&app, &instance, LogLevel::Info, line);
+            }
+        });
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let app = app.clone();
+        let instance = instance.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                push_log(&app, &instance, LogLevel::Error, line);
+            }
+        });
+    }
+
     Ok(EngineProcess::StdProcess(child))
 }
 
-/// Spawn engine using bundled sidecar binary (release mode)
+/// Spawn engine using bundled sidecar binary (release mode). `attempt` is the current
+/// restart attempt count, threaded through so an unexpected `Terminated` event can hand
+/// off to the supervisor with the right backoff.
 fn spawn_sidecar_engine(
     app: &AppHandle,
+    instance: &Arc<EngineInstance>,
     port: u16,
     token: &str,
     parent_pid: &str,
     log_level: &str,
+    attempt: u32,
 ) -> Result<EngineProcess, String> {
     use tauri_plugin_shell::process::CommandEvent;
 
@@ -171,23 +413,34 @@ fn spawn_sidecar_engine(
         .spawn()
         .map_err(|e| format!("Failed to spawn sidecar: {}", e))?;
 
-    // Spawn a task to handle output (relay to console)
+    let app_handle = app.clone();
+    let instance = instance.clone();
+
+    // Spawn a task to handle output (into the log buffer and `engine://log` events) and
+    // detect unexpected termination
     tauri::async_runtime::spawn(async move {
         while let Some(event) = rx.recv().await {
             match event {
                 CommandEvent::Stdout(line) => {
-                    let line = String::from_utf8_lossy(&line);
-                    print!("{}", line);
+                    let line = String::from_utf8_lossy(&line).trim_end().to_string();
+                    push_log(&app_handle, &instance, LogLevel::Info, line);
                 }
                 CommandEvent::Stderr(line) => {
-                    let line = String::from_utf8_lossy(&line);
-                    eprint!("{}", line);
+                    let line = String::from_utf8_lossy(&line).trim_end().to_string();
+                    push_log(&app_handle, &instance, LogLevel::Error, line);
                 }
                 CommandEvent::Error(err) => {
                     eprintln!("Engine error: {}", err);
                 }
                 CommandEvent::Terminated(status) => {
                     println!("Engine terminated with status: {:?}", status);
+                    supervise_restart(
+                        app_handle,
+                        instance,
+                        Some(format!("{:?}", status)),
+                        attempt,
+                    )
+                    .await;
                     break;
                 }
                 _ => {}
@@ -198,25 +451,236 @@ fn spawn_sidecar_engine(
     Ok(EngineProcess::SidecarProcess(child))
 }
 
-#[tauri::command]
-pub async fn start_engine(
+/// Spawn a monitor thread for a `StdProcess` that polls for exit (`Child` has no
+/// terminated-event channel like the sidecar does) and hands off to the supervisor
+/// the moment it notices the child has gone away.
+fn spawn_std_monitor(app: AppHandle, instance: Arc<EngineInstance>, attempt: u32) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_millis(300));
+
+        let exit_status = {
+            let mut guard = instance.process.lock().unwrap();
+            match guard.as_mut() {
+                Some(EngineProcess::StdProcess(child)) => match child.try_wait() {
+                    Ok(Some(status)) => Some(status.to_string()),
+                    Ok(None) => None,
+                    Err(e) => Some(format!("wait error: {}", e)),
+                },
+                // Process was replaced (restarted) or removed (stopped); this monitor
+                // is stale, let it die quietly.
+                _ => return,
+            }
+        };
+
+        if let Some(status) = exit_status {
+            tauri::async_runtime::spawn(supervise_restart(
+                app.clone(),
+                instance.clone(),
+                Some(status),
+                attempt,
+            ));
+            return;
+        }
+    });
+}
+
+/// Spawn the engine process appropriate for the current build, and wire up the
+/// monitoring needed to detect an unexpected exit for `attempt` (0 for the initial
+/// start, incrementing on each automatic restart).
+fn spawn_and_monitor(
+    app: &AppHandle,
+    instance: &Arc<EngineInstance>,
+    port: u16,
+    token: &str,
+    parent_pid: &str,
+    log_level: &str,
+    attempt: u32,
+) -> Result<EngineProcess, String> {
+    let process = if cfg!(debug_assertions) {
+        // Development mode: always use Python
+        spawn_python_engine(app, instance, port, token, parent_pid, log_level)
+    } else {
+        // Release mode: try sidecar first, fall back to Python
+        match spawn_sidecar_engine(app, instance, port, token, parent_pid, log_level, attempt) {
+            Ok(process) => Ok(process),
+            Err(sidecar_err) => {
+                eprintln!(
+                    "Sidecar not found, falling back to Python: {}",
+                    sidecar_err
+                );
+                spawn_python_engine(app, instance, port, token, parent_pid, log_level)
+            }
+        }
+    }?;
+
+    if let EngineProcess::StdProcess(_) = &process {
+        spawn_std_monitor(app.clone(), instance.clone(), attempt);
+    }
+
+    Ok(process)
+}
+
+/// Poll `{base_url}/health` until it responds with a 2xx status or `READY_TIMEOUT`
+/// elapses. The FastAPI backend needs time to bind its port after the process spawns
+/// (or, for a remote engine, simply to become reachable), so callers must wait for
+/// this before reporting `Running`.
+async fn wait_until_ready_at(base_url: &str, token: &str) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let deadline = tokio::time::Instant::now() + READY_TIMEOUT;
+
+    loop {
+        let mut request = client
+            .get(format!("{}/health", base_url))
+            .timeout(READY_PROBE_TIMEOUT);
+        if !token.is_empty() {
+            request = request.bearer_auth(token);
+        }
+
+        if let Ok(response) = request.send().await {
+            if response.status().is_success() {
+                return Ok(());
+            }
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return Err(format!(
+                "Engine did not become ready at {} within {:?}",
+                base_url, READY_TIMEOUT
+            ));
+        }
+
+        tokio::time::sleep(READY_POLL_INTERVAL).await;
+    }
+}
+
+/// Convenience wrapper over [`wait_until_ready_at`] for a locally spawned engine.
+async fn wait_until_ready(port: u16, token: &str) -> Result<(), String> {
+    wait_until_ready_at(&format!("http://127.0.0.1:{}", port), token).await
+}
+
+/// Best-effort extraction of the port from a `scheme://host:port[/path]` URL, used so
+/// `get_engine_port` still returns something sensible for a remote engine.
+fn parse_port_from_url(url: &str) -> Option<u16> {
+    let without_scheme = url.split("://").last()?;
+    let host_port = without_scheme.split('/').next()?;
+    host_port.rsplit(':').next()?.parse().ok()
+}
+
+/// Called when an engine exit was detected that wasn't requested via
+/// `stop_engine_instance`. Marks the engine `Error`, then retries `spawn_and_monitor`
+/// with a fresh port/token using exponential backoff, up to `MAX_RESTART_ATTEMPTS`.
+fn supervise_restart(
     app: AppHandle,
-    state: State<'_, EngineState>,
-) -> Result<u16, String> {
-    // Check if already running or starting
-    {
-        let status = state.status.lock().unwrap();
-        if *status == EngineStatus::Running || *status == EngineStatus::Starting {
-            let port = *state.port.lock().unwrap();
-            return Ok(port);
+    instance: Arc<EngineInstance>,
+    exit_status: Option<String>,
+    attempt: u32,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>> {
+    Box::pin(async move {
+        if instance.shutdown_requested.load(Ordering::SeqCst) {
+            instance.set_status(EngineStatus::Stopped);
+            return;
         }
+
+        // A crash of a `Running` engine whose retry count the stability window already
+        // reset to 0 starts the backoff afresh, whatever attempt its monitor was spawned with.
+        let attempt = if instance.status() == EngineStatus::Running
+            && *instance.retry_count.lock().unwrap() == 0
+        {
+            0
+        } else {
+            attempt
+        };
+
+        instance.set_status(EngineStatus::Error);
+        *instance.last_exit_status.lock().unwrap() = exit_status;
+        *instance.process.lock().unwrap() = None;
+        *instance.retry_count.lock().unwrap() = attempt;
+
+        if attempt >= MAX_RESTART_ATTEMPTS {
+            eprintln!(
+                "Engine '{}' crashed {} times in a row, giving up automatic restart",
+                instance.id, attempt
+            );
+            return;
+        }
+
+        let backoff_ms = BASE_BACKOFF_MS.saturating_mul(1u64 << attempt).min(MAX_BACKOFF_MS);
+        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+
+        if instance.shutdown_requested.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let engine_state = app.state::<EngineState>();
+        let port = match find_available_port(&engine_state.claimed_ports()) {
+            Ok(port) => port,
+            Err(e) => {
+                eprintln!("Engine '{}' restart attempt {} failed: {}", instance.id, attempt + 1, e);
+                supervise_restart(app, instance, Some(e), attempt + 1).await;
+                return;
+            }
+        };
+        let token = generate_token();
+        let log_level = if cfg!(debug_assertions) { "DEBUG" } else { "INFO" };
+        let parent_pid = std::process::id().to_string();
+        let next_attempt = attempt + 1;
+
+        match spawn_and_monitor(&app, &instance, port, &token, &parent_pid, log_level, next_attempt) {
+            Ok(process) => {
+                *instance.process.lock().unwrap() = Some(process);
+                instance.set_port(port);
+                *instance.token.lock().unwrap() = token.clone();
+                *instance.retry_count.lock().unwrap() = next_attempt;
+
+                if let Err(e) = wait_until_ready(port, &token).await {
+                    eprintln!("Engine '{}' restart attempt {} failed: {}", instance.id, next_attempt, e);
+                    if let Some(process) = instance.process.lock().unwrap().take() {
+                        let _ = process.kill();
+                    }
+                    supervise_restart(app, instance, Some(e), next_attempt).await;
+                    return;
+                }
+
+                instance.set_status(EngineStatus::Running);
+
+                // Reset the backoff counter once the engine survives past the stability window.
+                let stability_instance = instance.clone();
+                tauri::async_runtime::spawn(async move {
+                    tokio::time::sleep(STABILITY_WINDOW).await;
+                    if stability_instance.status() == EngineStatus::Running {
+                        let mut retry_count = stability_instance.retry_count.lock().unwrap();
+                        if *retry_count == next_attempt {
+                            *retry_count = 0;
+                        }
+                    }
+                });
+            }
+            Err(e) => {
+                eprintln!("Engine '{}' restart attempt {} failed: {}", instance.id, next_attempt, e);
+                supervise_restart(app, instance, Some(e), next_attempt).await;
+            }
+        }
+    })
+}
+
+/// Start the named engine instance (creating its registry entry if needed), returning
+/// the port it's listening on once the readiness probe succeeds.
+async fn start_instance(app: AppHandle, state: &EngineState, id: &str) -> Result<u16, String> {
+    let instance = state.get_or_create(id);
+
+    // Check if already running or starting
+    if matches!(instance.status(), EngineStatus::Running | EngineStatus::Starting) {
+        return Ok(instance.port());
     }
 
     // Update status to starting
-    *state.status.lock().unwrap() = EngineStatus::Starting;
+    instance.set_status(EngineStatus::Starting);
+    instance.shutdown_requested.store(false, Ordering::SeqCst);
+    *instance.retry_count.lock().unwrap() = 0;
+    *instance.last_exit_status.lock().unwrap() = None;
 
     // Find available port and generate token
-    let port = find_available_port()?;
+    let port = find_available_port(&state.claimed_ports())?;
     let token = generate_token();
 
     // In development, use DEBUG log level; in production use INFO
@@ -228,54 +692,68 @@ pub async fn start_engine(
 
     let parent_pid = std::process::id().to_string();
 
-    // In release builds, use the bundled sidecar binary
-    // In debug builds, use Python directly for easier development
-    let process_result = if cfg!(debug_assertions) {
-        // Development mode: always use Python
-        spawn_python_engine(port, &token, &parent_pid, log_level)
-    } else {
-        // Release mode: try sidecar first, fall back to Python
-        match spawn_sidecar_engine(&app, port, &token, &parent_pid, log_level) {
-            Ok(process) => Ok(process),
-            Err(sidecar_err) => {
-                eprintln!(
-                    "Sidecar not found, falling back to Python: {}",
-                    sidecar_err
-                );
-                spawn_python_engine(port, &token, &parent_pid, log_level)
-            }
-        }
-    };
-
-    let process = match process_result {
+    let process = match spawn_and_monitor(&app, &instance, port, &token, &parent_pid, log_level, 0) {
         Ok(process) => process,
         Err(err) => {
-            *state.status.lock().unwrap() = EngineStatus::Error;
-            *state.process.lock().unwrap() = None;
-            *state.token.lock().unwrap() = String::new();
+            instance.set_status(EngineStatus::Error);
+            *instance.process.lock().unwrap() = None;
+            *instance.token.lock().unwrap() = String::new();
             return Err(err);
         }
     };
 
     // Store state
-    *state.process.lock().unwrap() = Some(process);
-    *state.port.lock().unwrap() = port;
-    *state.token.lock().unwrap() = token;
-    *state.status.lock().unwrap() = EngineStatus::Running;
+    *instance.process.lock().unwrap() = Some(process);
+    instance.set_port(port);
+    *instance.token.lock().unwrap() = token.clone();
+
+    // Don't report Running until the engine's HTTP server actually answers.
+    if let Err(e) = wait_until_ready(port, &token).await {
+        if let Some(process) = instance.process.lock().unwrap().take() {
+            let _ = process.kill();
+        }
+        instance.set_status(EngineStatus::Error);
+        *instance.token.lock().unwrap() = String::new();
+        return Err(e);
+    }
+
+    instance.set_status(EngineStatus::Running);
 
     Ok(port)
 }
 
-#[tauri::command]
-pub async fn stop_engine(state: State<'_, EngineState>) -> Result<(), String> {
-    let port = *state.port.lock().unwrap();
-    let token = state.token.lock().unwrap().clone();
+/// Stop the named engine instance. `shutdown_remote` controls whether a `Remote`
+/// instance's external process is actually told to shut down, or just detached from.
+async fn stop_instance(state: &EngineState, id: &str, shutdown_remote: bool) -> Result<(), String> {
+    let instance = state.get_or_create(id);
+
+    // Mark this as a deliberate stop so the supervisor doesn't try to restart it.
+    instance.shutdown_requested.store(true, Ordering::SeqCst);
+
+    let base_url = match &*instance.process.lock().unwrap() {
+        Some(EngineProcess::Remote { base_url }) => Some(base_url.clone()),
+        _ => None,
+    };
+
+    // Detaching from a remote engine leaves it running unless the caller opts in.
+    if base_url.is_some() && !shutdown_remote {
+        *instance.process.lock().unwrap() = None;
+        instance.set_status(EngineStatus::Stopped);
+        *instance.token.lock().unwrap() = String::new();
+        *instance.retry_count.lock().unwrap() = 0;
+        return Ok(());
+    }
+
+    let port = instance.port();
+    let token = instance.token.lock().unwrap().clone();
+    let shutdown_url = match &base_url {
+        Some(base_url) => format!("{}/shutdown", base_url),
+        None => format!("http://127.0.0.1:{}/shutdown", port),
+    };
 
     // Try graceful shutdown first via HTTP
     let client = reqwest::Client::new();
-    let mut request = client
-        .post(format!("http://127.0.0.1:{}/shutdown", port))
-        .timeout(Duration::from_secs(3));
+    let mut request = client.post(shutdown_url).timeout(Duration::from_secs(3));
     if !token.is_empty() {
         request = request.bearer_auth(token);
     }
@@ -284,31 +762,146 @@ pub async fn stop_engine(state: State<'_, EngineState>) -> Result<(), String> {
     // Wait a bit for graceful shutdown
     tokio::time::sleep(Duration::from_millis(500)).await;
 
-    // Kill process if still running
-    if let Some(process) = state.process.lock().unwrap().take() {
+    // Kill process if still running (a no-op for a remote engine)
+    if let Some(process) = instance.process.lock().unwrap().take() {
         let _ = process.kill();
     }
 
-    *state.status.lock().unwrap() = EngineStatus::Stopped;
-    *state.token.lock().unwrap() = String::new();
+    instance.set_status(EngineStatus::Stopped);
+    *instance.token.lock().unwrap() = String::new();
+    *instance.retry_count.lock().unwrap() = 0;
 
     Ok(())
 }
 
+#[tauri::command]
+pub async fn start_engine(app: AppHandle, state: State<'_, EngineState>) -> Result<u16, String> {
+    start_instance(app, &state, DEFAULT_ENGINE_ID).await
+}
+
+/// Start a named engine instance, for running several engines concurrently.
+#[tauri::command]
+pub async fn start_engine_instance(
+    app: AppHandle,
+    state: State<'_, EngineState>,
+    id: String,
+) -> Result<u16, String> {
+    start_instance(app, &state, &id).await
+}
+
+/// Attach to an engine already running elsewhere (another host, a container) instead
+/// of spawning a local subprocess. Validates `{url}/health` before reporting `Running`,
+/// same as the local readiness probe.
+#[tauri::command]
+pub async fn connect_remote_engine(
+    app: AppHandle,
+    state: State<'_, EngineState>,
+    url: String,
+    token: String,
+) -> Result<(), String> {
+    let instance = state.get_or_create(DEFAULT_ENGINE_ID);
+
+    // Refuse to clobber a live local process: dropping its `EngineProcess` here would
+    // orphan the child (`Child`'s `Drop` doesn't kill it) with no handle left to stop it.
+    if matches!(instance.status(), EngineStatus::Running | EngineStatus::Starting) {
+        return Err(
+            "An engine is already running on this id; stop it before connecting to a remote engine"
+                .to_string(),
+        );
+    }
+
+    let base_url = url.trim_end_matches('/').to_string();
+
+    instance.set_status(EngineStatus::Starting);
+    instance.shutdown_requested.store(false, Ordering::SeqCst);
+    *instance.retry_count.lock().unwrap() = 0;
+    *instance.last_exit_status.lock().unwrap() = None;
+
+    if let Err(e) = wait_until_ready_at(&base_url, &token).await {
+        instance.set_status(EngineStatus::Error);
+        return Err(e);
+    }
+
+    push_log(
+        &app,
+        &instance,
+        LogLevel::Info,
+        format!("Connected to remote engine at {}", base_url),
+    );
+
+    *instance.process.lock().unwrap() = Some(EngineProcess::Remote {
+        base_url: base_url.clone(),
+    });
+    *instance.token.lock().unwrap() = token;
+    instance.set_port(parse_port_from_url(&base_url).unwrap_or(0));
+    instance.set_status(EngineStatus::Running);
+
+    Ok(())
+}
+
+/// `shutdown_remote` defaults to `false` so frontend call sites predating `connect_remote_engine`
+/// (which invoke this with no arguments) keep working unchanged for the ordinary local-engine case.
+#[tauri::command]
+pub async fn stop_engine(
+    state: State<'_, EngineState>,
+    shutdown_remote: Option<bool>,
+) -> Result<(), String> {
+    stop_instance(&state, DEFAULT_ENGINE_ID, shutdown_remote.unwrap_or(false)).await
+}
+
+/// Stop a named engine instance.
+#[tauri::command]
+pub async fn stop_engine_instance(
+    state: State<'_, EngineState>,
+    id: String,
+    shutdown_remote: bool,
+) -> Result<(), String> {
+    stop_instance(&state, &id, shutdown_remote).await
+}
+
+// These four read the default instance's `Arc` directly off `EngineState`, never touching
+// the `instances` map lock, so reads of `EngineInstance::port`/`status` stay lock-free.
+
 #[tauri::command]
 pub fn get_engine_port(state: State<'_, EngineState>) -> u16 {
-    *state.port.lock().unwrap()
+    state.default_instance.port()
 }
 
 #[tauri::command]
 pub fn get_engine_status(state: State<'_, EngineState>) -> EngineInfo {
-    EngineInfo {
-        port: *state.port.lock().unwrap(),
-        status: *state.status.lock().unwrap(),
-    }
+    state.default_instance.info()
 }
 
 #[tauri::command]
 pub fn get_engine_token(state: State<'_, EngineState>) -> String {
-    state.token.lock().unwrap().clone()
+    state.default_instance.token.lock().unwrap().clone()
+}
+
+fn instance_logs(instance: &EngineInstance, limit: usize) -> Vec<LogLine> {
+    let buffer = instance.log_buffer.lock().unwrap();
+    let skip = buffer.len().saturating_sub(limit);
+    buffer.iter().skip(skip).cloned().collect()
+}
+
+/// Return up to the last `limit` buffered log lines, oldest first, so a freshly opened
+/// log panel can backfill history before live `engine://log` events start arriving.
+#[tauri::command]
+pub fn get_engine_logs(state: State<'_, EngineState>, limit: usize) -> Vec<LogLine> {
+    instance_logs(&state.default_instance, limit)
+}
+
+/// Same as `get_engine_logs`, but for a named instance — needed once a UI shows a pool
+/// of engines, since the live `engine://log` event is the only other place an id shows up.
+#[tauri::command]
+pub fn get_engine_logs_instance(state: State<'_, EngineState>, id: String, limit: usize) -> Vec<LogLine> {
+    instance_logs(&state.get_or_create(&id), limit)
+}
+
+/// List every known engine instance (running or not), for a UI that wants to show and
+/// manage a pool of engines rather than just the default one.
+#[tauri::command]
+pub fn list_engines(state: State<'_, EngineState>) -> Vec<EngineInfo> {
+    std::iter::once(state.default_instance.info())
+        .chain(state.instances.lock().unwrap().values().map(|instance| instance.info()))
+        .collect()
 }
@@ -27,10 +27,16 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             engine::start_engine,
+            engine::start_engine_instance,
+            engine::connect_remote_engine,
             engine::stop_engine,
+            engine::stop_engine_instance,
             engine::get_engine_port,
             engine::get_engine_status,
             engine::get_engine_token,
+            engine::get_engine_logs,
+            engine::get_engine_logs_instance,
+            engine::list_engines,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");